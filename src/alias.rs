@@ -0,0 +1,9 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::chunk::Chunk;
+use crate::value::Value;
+
+pub type StoredChunk = Rc<RefCell<Chunk>>;
+pub type StoredValue = Rc<RefCell<Value>>;
+pub type VoidResult = Result<(), Box<dyn std::error::Error>>;
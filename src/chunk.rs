@@ -0,0 +1,65 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias::StoredValue;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum OpCode {
+    Const { line: usize, const_idx: usize },
+    Negate { line: usize },
+    Not { line: usize },
+    Add { line: usize },
+    Sub { line: usize },
+    Mul { line: usize },
+    Div { line: usize },
+    Equal { line: usize },
+    Greater { line: usize },
+    Less { line: usize },
+    Pop { line: usize },
+    Print { line: usize },
+    DefineGlobal { line: usize, const_idx: usize },
+    GetGlobal { line: usize, const_idx: usize },
+    SetGlobal { line: usize, const_idx: usize },
+    GetLocal { line: usize, slot: usize },
+    SetLocal { line: usize, slot: usize },
+}
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Not `Serialize`/`Deserialize` itself: `constants` holds `Rc<RefCell<Value>>`
+/// handles, which serde can't (de)serialize without the `rc` feature. Callers
+/// that need a persisted form (see `persist.rs`) serialize the plain `Value`s
+/// instead and rebuild the `Rc<RefCell<_>>` wrappers on load.
+#[derive(Clone, Default, Debug)]
+pub struct Chunk {
+    code: Vec<OpCode>,
+    constants: Vec<StoredValue>,
+}
+
+impl Chunk {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, op_code: OpCode) {
+        self.code.push(op_code);
+    }
+
+    pub fn push_const(&mut self, value: StoredValue) -> usize {
+        self.constants.push(value);
+        self.constants.len() - 1
+    }
+
+    pub fn code(&self) -> &[OpCode] {
+        &self.code
+    }
+
+    pub fn constants(&self) -> &[StoredValue] {
+        &self.constants
+    }
+}
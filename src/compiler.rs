@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+
 use crate::{
     alias::{StoredChunk, StoredValue, VoidResult},
     chunk::OpCode,
     errors::ParsingError,
+    interner::{InternedStr, Interner},
     parser::Parser,
     rc_refcell,
     scanner::Scanner,
@@ -15,7 +18,25 @@ pub struct Compiler {
     parser: Parser,
     scanner: Scanner,
     current_chunk: Option<StoredChunk>,
-    debug_mode: bool,
+    interner: Interner,
+    string_constants: HashMap<InternedStr, usize>,
+    locals: Locals,
+    panic: bool,
+    errors: Vec<ParsingError>,
+}
+
+/// `None` marks a local whose initializer hasn't finished compiling yet.
+type Depth = Option<usize>;
+
+struct Local {
+    name: Token,
+    depth: Depth,
+}
+
+#[derive(Default)]
+struct Locals {
+    locals: Vec<Local>,
+    scope_depth: usize,
 }
 
 #[derive(Copy, Clone, FromRepr, Debug)]
@@ -34,7 +55,7 @@ enum Precedence {
     Primary,
 }
 
-type ParseFn = fn(&mut Compiler) -> VoidResult;
+type ParseFn = fn(&mut Compiler, bool) -> VoidResult;
 
 #[derive(Debug)]
 struct ParseRule {
@@ -113,15 +134,15 @@ const RULES: [ParseRule; 41] = [
     },
     /* TOKEN_BANG */
     ParseRule {
-        prefix: None,
+        prefix: Some(Compiler::unary),
         infix: None,
         precedence: NONE,
     },
     /* TOKEN_BANG_EQUAL */
     ParseRule {
         prefix: None,
-        infix: None,
-        precedence: NONE,
+        infix: Some(Compiler::binary),
+        precedence: Eq,
     },
     /* TOKEN_EQUAL */
     ParseRule {
@@ -132,32 +153,32 @@ const RULES: [ParseRule; 41] = [
     /* TOKEN_EQUAL_EQUAL */
     ParseRule {
         prefix: None,
-        infix: None,
-        precedence: NONE,
+        infix: Some(Compiler::binary),
+        precedence: Eq,
     },
     /* TOKEN_GREATER */
     ParseRule {
         prefix: None,
-        infix: None,
-        precedence: NONE,
+        infix: Some(Compiler::binary),
+        precedence: Comp,
     },
     /* TOKEN_GREATER_EQUAL */
     ParseRule {
         prefix: None,
-        infix: None,
-        precedence: NONE,
+        infix: Some(Compiler::binary),
+        precedence: Comp,
     },
     /* TOKEN_LESS */
     ParseRule {
         prefix: None,
-        infix: None,
-        precedence: NONE,
+        infix: Some(Compiler::binary),
+        precedence: Comp,
     },
     /* TOKEN_LESS_EQUAL */
     ParseRule {
         prefix: None,
-        infix: None,
-        precedence: NONE,
+        infix: Some(Compiler::binary),
+        precedence: Comp,
     },
     /* TOKEN_SLASH_EQUAL */
     ParseRule {
@@ -167,13 +188,13 @@ const RULES: [ParseRule; 41] = [
     },
     /* TOKEN_IDENTIFIER */
     ParseRule {
-        prefix: None,
+        prefix: Some(Compiler::variable),
         infix: None,
         precedence: NONE,
     },
     /* TOKEN_STRING */
     ParseRule {
-        prefix: None,
+        prefix: Some(Compiler::string),
         infix: None,
         precedence: NONE,
     },
@@ -203,7 +224,7 @@ const RULES: [ParseRule; 41] = [
     },
     /* TOKEN_FALSE */
     ParseRule {
-        prefix: None,
+        prefix: Some(Compiler::literal),
         infix: None,
         precedence: NONE,
     },
@@ -227,7 +248,7 @@ const RULES: [ParseRule; 41] = [
     },
     /* TOKEN_NIL */
     ParseRule {
-        prefix: None,
+        prefix: Some(Compiler::literal),
         infix: None,
         precedence: NONE,
     },
@@ -263,7 +284,7 @@ const RULES: [ParseRule; 41] = [
     },
     /* TOKEN_TRUE */
     ParseRule {
-        prefix: None,
+        prefix: Some(Compiler::literal),
         infix: None,
         precedence: NONE,
     },
@@ -294,23 +315,47 @@ const RULES: [ParseRule; 41] = [
 ];
 
 impl Compiler {
-    pub fn from_source(source: String, debug_mode: bool) -> Self {
+    pub fn from_source(source: String) -> Self {
         let scanner = Scanner::new(source);
         let parser = Parser::new();
         Self {
             parser,
             scanner,
             current_chunk: None,
-            debug_mode,
+            interner: Interner::new(),
+            string_constants: HashMap::new(),
+            locals: Locals::default(),
+            panic: false,
+            errors: Vec::new(),
         }
     }
 
     pub fn compile(&mut self, chunk: StoredChunk) -> VoidResult {
         self.current_chunk = Some(chunk.clone());
 
-        self.advance()?;
-        self.expression()?;
-        self.consume(TokenType::EOF, "Expected end of expression".to_owned())
+        let _ = self.advance();
+        if self.panic {
+            let _ = self.synchronize();
+        }
+        while !self.match_token(TokenType::EOF)? {
+            self.declaration()?;
+        }
+
+        #[cfg(feature = "disassemble")]
+        crate::disassembler::disassemble_chunk(&chunk.borrow(), "code", &self.interner);
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ParsingError {}.into())
+        }
+    }
+
+    /// The strings interned while compiling (string literals and global
+    /// variable names), in handle order. Lets
+    /// callers ship the table alongside a persisted chunk.
+    pub fn interner_strings(&self) -> &[String] {
+        self.interner.strings()
     }
 
     fn previous(&self) -> Option<&Token> {
@@ -321,17 +366,6 @@ impl Compiler {
         self.parser.current.as_ref()
     }
 
-    fn debug_string(&self) -> String {
-        match (self.current(), self.previous()) {
-            (None, None) => String::from("current: None, previous: None"),
-            (None, Some(prev)) => format!("current: None, previous: {prev}"),
-            (Some(curr), None) => format!("current: {curr}, previous: None"),
-            (Some(curr), Some(prev)) => {
-                format!("current: {curr}, previous: {prev}")
-            }
-        }
-    }
-
     fn advance(&mut self) -> VoidResult {
         self.parser.previous = self.current().cloned();
         let new_token = self.scanner.scan_token();
@@ -342,9 +376,6 @@ impl Compiler {
         };
 
         self.parser.current = Some(new_token);
-        if self.debug_mode {
-            println!("Called advance(), {}", self.debug_string(),);
-        }
 
         match self.current().unwrap().token_type {
             TokenType::Error => self.error_at_current(message.unwrap()),
@@ -352,15 +383,22 @@ impl Compiler {
         }
     }
 
-    fn error_at_current(&self, message: String) -> VoidResult {
-        self.error_at(self.current().unwrap(), message)
+    fn error_at_current(&mut self, message: String) -> VoidResult {
+        let token = self.current().unwrap().clone();
+        self.error_at(&token, message)
     }
 
-    fn error(&self, message: String) -> VoidResult {
-        self.error_at(self.previous().unwrap(), message)
+    fn error(&mut self, message: String) -> VoidResult {
+        let token = self.previous().unwrap().clone();
+        self.error_at(&token, message)
     }
 
-    fn error_at(&self, token: &Token, message: String) -> VoidResult {
+    fn error_at(&mut self, token: &Token, message: String) -> VoidResult {
+        if self.panic {
+            return Err(ParsingError {}.into());
+        }
+        self.panic = true;
+
         print!("[line {}] Error", token.line);
         match token.token_type {
             TokenType::EOF => print!(" at end"),
@@ -371,6 +409,8 @@ impl Compiler {
             ),
         };
         println!(": {message}");
+
+        self.errors.push(ParsingError {});
         Err(ParsingError {}.into())
     }
 
@@ -383,9 +423,6 @@ impl Compiler {
     }
 
     fn emit_op_code(&self, op_code: OpCode) {
-        if self.debug_mode {
-            println!("Emitted opcode: {op_code}")
-        }
         self.current_chunk
             .as_ref()
             .unwrap()
@@ -413,13 +450,10 @@ impl Compiler {
     }
 
     fn expression(&mut self) -> VoidResult {
-        if self.debug_mode {
-            println!("Called expression(), {}", self.debug_string());
-        }
         self.parse_precedence(Precedence::Assignment)
     }
 
-    fn number(&mut self) -> VoidResult {
+    fn number(&mut self, _can_assign: bool) -> VoidResult {
         let value = Value::Float(
             self.previous()
                 .unwrap()
@@ -429,28 +463,66 @@ impl Compiler {
                 .parse::<f64>()
                 .unwrap(),
         );
-        if self.debug_mode {
-            println!("Called number() for {}", value);
-        }
         self.emit_const(rc_refcell!(value));
         Ok(())
     }
 
-    fn grouping(&mut self) -> VoidResult {
+    fn string(&mut self, _can_assign: bool) -> VoidResult {
+        let token = self.previous().unwrap();
+        let lexeme = self
+            .scanner
+            .substr(token.start + 1, token.start + token.length - 1);
+        let handle = self.interner.intern(&lexeme);
+        let const_idx = self.const_for_interned(handle);
+        self.emit_op_code(OpCode::Const {
+            line: self.line(),
+            const_idx,
+        });
+        Ok(())
+    }
+
+    /// Returns the constant-pool index for an interned string, reusing the
+    /// existing slot if this handle was already emitted as a constant.
+    fn const_for_interned(&mut self, handle: InternedStr) -> usize {
+        if let Some(&idx) = self.string_constants.get(&handle) {
+            return idx;
+        }
+        let idx = self.make_const(rc_refcell!(Value::Str(handle)));
+        self.string_constants.insert(handle, idx);
+        idx
+    }
+
+    fn identifier_constant(&mut self, name: &Token) -> usize {
+        let lexeme = self.scanner.substr(name.start, name.start + name.length);
+        let handle = self.interner.intern(&lexeme);
+        self.const_for_interned(handle)
+    }
+
+    fn grouping(&mut self, _can_assign: bool) -> VoidResult {
         self.expression()?;
         self.consume(TokenType::RightParen, "Expected ')'".to_owned())
     }
 
-    fn unary(&mut self) -> VoidResult {
+    fn unary(&mut self, _can_assign: bool) -> VoidResult {
         let op_type = &self.previous().unwrap().token_type.clone();
         self.parse_precedence(Precedence::Unary)?;
 
-        if self.debug_mode {
-            println!("Called unary for op {:?}, {}", op_type, self.debug_string(),)
+        match op_type {
+            TokenType::MINUS => self.emit_op_code(OpCode::Negate { line: self.line() }),
+            TokenType::Bang => self.emit_op_code(OpCode::Not { line: self.line() }),
+            _ => {}
         }
+        Ok(())
+    }
 
-        if op_type == &TokenType::MINUS {
-            self.emit_op_code(OpCode::Negate { line: self.line() })
+    fn literal(&mut self, _can_assign: bool) -> VoidResult {
+        let op_type = self.previous().unwrap().token_type;
+
+        match op_type {
+            TokenType::True => self.emit_const(rc_refcell!(Value::Bool(true))),
+            TokenType::False => self.emit_const(rc_refcell!(Value::Bool(false))),
+            TokenType::Nil => self.emit_const(rc_refcell!(Value::Nil)),
+            _ => panic!("Unsupported literal token"),
         }
         Ok(())
     }
@@ -466,20 +538,11 @@ impl Compiler {
         (RULES.get(idx).unwrap()) as _
     }
 
-    fn binary(&mut self) -> VoidResult {
+    fn binary(&mut self, _can_assign: bool) -> VoidResult {
         let op_type = &self.previous().unwrap().token_type.clone();
         let rule = self.get_rule(op_type);
         let next_precedence = self.next_precedence(rule.precedence);
 
-        if self.debug_mode {
-            println!(
-                "Called binary {:?}, {}, next precedence = {:?}",
-                op_type,
-                self.debug_string(),
-                next_precedence
-            )
-        }
-
         self.parse_precedence(next_precedence)?;
 
         match op_type {
@@ -499,68 +562,367 @@ impl Compiler {
                 self.emit_op_code(OpCode::Mul { line: self.line() });
                 Ok(())
             }
+            TokenType::EqualEqual => {
+                self.emit_op_code(OpCode::Equal { line: self.line() });
+                Ok(())
+            }
+            TokenType::BangEqual => {
+                self.emit_op_code(OpCode::Equal { line: self.line() });
+                self.emit_op_code(OpCode::Not { line: self.line() });
+                Ok(())
+            }
+            TokenType::Greater => {
+                self.emit_op_code(OpCode::Greater { line: self.line() });
+                Ok(())
+            }
+            TokenType::GreaterEqual => {
+                self.emit_op_code(OpCode::Less { line: self.line() });
+                self.emit_op_code(OpCode::Not { line: self.line() });
+                Ok(())
+            }
+            TokenType::Less => {
+                self.emit_op_code(OpCode::Less { line: self.line() });
+                Ok(())
+            }
+            TokenType::LessEqual => {
+                self.emit_op_code(OpCode::Greater { line: self.line() });
+                self.emit_op_code(OpCode::Not { line: self.line() });
+                Ok(())
+            }
             _ => panic!("Unsupported binary token"),
         }
     }
 
     fn parse_precedence(&mut self, precedence: Precedence) -> VoidResult {
-        if self.debug_mode {
-            println!(
-                "Called parse_precedence() with precedence = {:?}, {}",
-                precedence,
-                self.debug_string(),
-            )
-        }
         self.advance()?;
         let Some(prefix_rule) = self.get_rule(&self.previous().unwrap().token_type).prefix else {
             return self.error("Expected expression".to_owned());
         };
 
-        prefix_rule(self)?;
-
-        let current_token_precedence = self
-            .get_rule(&self.current().unwrap().token_type)
-            .precedence as usize;
-
-        if !(precedence as usize <= current_token_precedence) {
-            println!(
-                "Skipping infix rule loop, {}, precedence: {:?}({}), current precedence: {:?}({})",
-                self.debug_string(),
-                precedence,
-                precedence as usize,
-                self.get_rule(&self.current().unwrap().token_type)
-                    .precedence,
-                self.get_rule(&self.current().unwrap().token_type)
-                    .precedence as usize,
-            );
-        }
+        let can_assign = precedence as usize <= Precedence::Assignment as usize;
+        prefix_rule(self, can_assign)?;
 
-        let _: () = while (precedence as usize)
+        while (precedence as usize)
             <= (self
                 .get_rule(&self.current().unwrap().token_type)
                 .precedence as usize)
         {
-            if self.debug_mode {
-                println!(
-                    "Inside infix rule loop, precedence: {:?}({}), current precedence: {:?}({}), {}",
-                    precedence,
-                    precedence as usize,
-                    self.get_rule(&self.current().unwrap().token_type)
-                        .precedence,
-                    self.get_rule(&self.current().unwrap().token_type)
-                        .precedence as usize,
-                    self.debug_string()
-                )
-            }
             self.advance()?;
             let Some(infix_rule) = self.get_rule(&self.previous().unwrap().token_type).infix else {
                 continue;
             };
-            if self.debug_mode {
-                println!("Calling infix rule for {}", self.previous().unwrap())
+            infix_rule(self, can_assign)?;
+        }
+
+        if can_assign && self.match_token(TokenType::Equal)? {
+            return self.error("Invalid assignment target".to_owned());
+        }
+        Ok(())
+    }
+
+    fn check(&self, token_type: TokenType) -> bool {
+        self.current().unwrap().token_type == token_type
+    }
+
+    fn match_token(&mut self, token_type: TokenType) -> Result<bool, Box<dyn std::error::Error>> {
+        if !self.check(token_type) {
+            return Ok(false);
+        }
+        self.advance()?;
+        Ok(true)
+    }
+
+    fn declaration(&mut self) -> VoidResult {
+        let _ = self.declaration_inner();
+
+        if self.panic {
+            let _ = self.synchronize();
+        }
+        Ok(())
+    }
+
+    fn declaration_inner(&mut self) -> VoidResult {
+        if self.match_token(TokenType::Var)? {
+            self.var_declaration()
+        } else {
+            self.statement()
+        }
+    }
+
+    fn synchronize(&mut self) -> VoidResult {
+        self.panic = false;
+
+        while self.current().unwrap().token_type != TokenType::EOF {
+            if self
+                .previous()
+                .is_some_and(|token| token.token_type == TokenType::Semicolon)
+            {
+                return Ok(());
             }
-            infix_rule(self)?;
-        };
+
+            match self.current().unwrap().token_type {
+                TokenType::Class
+                | TokenType::Fun
+                | TokenType::Var
+                | TokenType::For
+                | TokenType::If
+                | TokenType::While
+                | TokenType::Print
+                | TokenType::Return => return Ok(()),
+                _ => {}
+            }
+
+            self.advance()?;
+        }
+        Ok(())
+    }
+
+    fn statement(&mut self) -> VoidResult {
+        if self.match_token(TokenType::Print)? {
+            self.print_statement()
+        } else if self.match_token(TokenType::LeftBrace)? {
+            self.begin_scope();
+            self.block()?;
+            self.end_scope();
+            Ok(())
+        } else {
+            self.expression_statement()
+        }
+    }
+
+    fn block(&mut self) -> VoidResult {
+        while !self.check(TokenType::RightBrace) && !self.check(TokenType::EOF) {
+            self.declaration()?;
+        }
+        self.consume(TokenType::RightBrace, "Expected '}' after block.".to_owned())
+    }
+
+    fn begin_scope(&mut self) {
+        self.locals.scope_depth += 1;
+    }
+
+    fn end_scope(&mut self) {
+        self.locals.scope_depth -= 1;
+
+        while let Some(local) = self.locals.locals.last() {
+            if local.depth.is_some_and(|depth| depth > self.locals.scope_depth) {
+                self.emit_op_code(OpCode::Pop { line: self.line() });
+                self.locals.locals.pop();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn print_statement(&mut self) -> VoidResult {
+        self.expression()?;
+        self.consume(
+            TokenType::Semicolon,
+            "Expected ';' after value.".to_owned(),
+        )?;
+        self.emit_op_code(OpCode::Print { line: self.line() });
+        Ok(())
+    }
+
+    fn expression_statement(&mut self) -> VoidResult {
+        self.expression()?;
+        self.consume(
+            TokenType::Semicolon,
+            "Expected ';' after expression.".to_owned(),
+        )?;
+        self.emit_op_code(OpCode::Pop { line: self.line() });
+        Ok(())
+    }
+
+    fn var_declaration(&mut self) -> VoidResult {
+        let const_idx = self.parse_variable("Expected variable name.".to_owned())?;
+
+        if self.match_token(TokenType::Equal)? {
+            self.expression()?;
+        } else {
+            self.emit_const(rc_refcell!(Value::Nil));
+        }
+
+        self.consume(
+            TokenType::Semicolon,
+            "Expected ';' after variable declaration.".to_owned(),
+        )?;
+
+        self.define_variable(const_idx);
+        Ok(())
+    }
+
+    fn parse_variable(&mut self, message: String) -> Result<usize, Box<dyn std::error::Error>> {
+        self.consume(TokenType::Identifier, message)?;
+
+        self.declare_variable()?;
+        if self.locals.scope_depth > 0 {
+            return Ok(0);
+        }
+
+        Ok(self.identifier_constant(&self.previous().unwrap().clone()))
+    }
+
+    fn declare_variable(&mut self) -> VoidResult {
+        if self.locals.scope_depth == 0 {
+            return Ok(());
+        }
+
+        let name = self.previous().unwrap().clone();
+        let name_lexeme = self.scanner.substr(name.start, name.start + name.length);
+
+        for local in self.locals.locals.iter().rev() {
+            if local.depth.is_some_and(|depth| depth < self.locals.scope_depth) {
+                break;
+            }
+            let local_lexeme = self
+                .scanner
+                .substr(local.name.start, local.name.start + local.name.length);
+            if local_lexeme == name_lexeme {
+                return self
+                    .error("Already a variable with this name in this scope.".to_owned());
+            }
+        }
+
+        self.locals.locals.push(Local { name, depth: None });
+        Ok(())
+    }
+
+    fn define_variable(&mut self, const_idx: usize) {
+        if self.locals.scope_depth > 0 {
+            let depth = self.locals.scope_depth;
+            self.locals.locals.last_mut().unwrap().depth = Some(depth);
+            return;
+        }
+
+        self.emit_op_code(OpCode::DefineGlobal {
+            line: self.line(),
+            const_idx,
+        });
+    }
+
+    fn resolve_local(&mut self, name: &Token) -> Result<Option<usize>, Box<dyn std::error::Error>> {
+        let name_lexeme = self.scanner.substr(name.start, name.start + name.length);
+
+        for (slot, local) in self.locals.locals.iter().enumerate().rev() {
+            let local_lexeme = self
+                .scanner
+                .substr(local.name.start, local.name.start + local.name.length);
+            if local_lexeme == name_lexeme {
+                if local.depth.is_none() {
+                    return self
+                        .error("Can't read local variable in its own initializer".to_owned())
+                        .map(|_| None);
+                }
+                return Ok(Some(slot));
+            }
+        }
+        Ok(None)
+    }
+
+    fn variable(&mut self, can_assign: bool) -> VoidResult {
+        let name = self.previous().unwrap().clone();
+        let local_slot = self.resolve_local(&name)?;
+
+        if can_assign && self.match_token(TokenType::Equal)? {
+            self.expression()?;
+            match local_slot {
+                Some(slot) => self.emit_op_code(OpCode::SetLocal {
+                    line: self.line(),
+                    slot,
+                }),
+                None => {
+                    let const_idx = self.identifier_constant(&name);
+                    self.emit_op_code(OpCode::SetGlobal {
+                        line: self.line(),
+                        const_idx,
+                    });
+                }
+            }
+        } else {
+            match local_slot {
+                Some(slot) => self.emit_op_code(OpCode::GetLocal {
+                    line: self.line(),
+                    slot,
+                }),
+                None => {
+                    let const_idx = self.identifier_constant(&name);
+                    self.emit_op_code(OpCode::GetGlobal {
+                        line: self.line(),
+                        const_idx,
+                    });
+                }
+            }
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk::Chunk;
+
+    fn compile_source(source: &str) -> (VoidResult, StoredChunk) {
+        let mut compiler = Compiler::from_source(source.to_owned());
+        let chunk: StoredChunk = rc_refcell!(Chunk::new());
+        let result = compiler.compile(chunk.clone());
+        (result, chunk)
+    }
+
+    #[test]
+    fn local_cannot_reference_itself_in_initializer() {
+        let (result, _chunk) = compile_source("{ var a = a; }");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn duplicate_local_in_same_scope_errors() {
+        let (result, _chunk) = compile_source("{ var a = 1; var a = 2; }");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn shadowing_in_nested_scope_is_allowed() {
+        let (result, _chunk) = compile_source("{ var a = 1; { var a = 2; } }");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn nested_scope_shadowing_resolves_to_correct_slots() {
+        let (result, chunk) = compile_source("{ var a = 1; { var a = 2; a = 3; } a = 4; }");
+        assert!(result.is_ok());
+
+        let set_local_slots: Vec<usize> = chunk
+            .borrow()
+            .code()
+            .iter()
+            .filter_map(|op| match op {
+                OpCode::SetLocal { slot, .. } => Some(*slot),
+                _ => None,
+            })
+            .collect();
+
+        // The inner `a = 3` resolves to the shadowing local (slot 1); once
+        // that scope ends and its local is popped, the outer `a = 4` again
+        // resolves to the outer local (slot 0).
+        assert_eq!(set_local_slots, vec![1, 0]);
+    }
+
+    #[test]
+    fn lexer_error_before_first_declaration_still_recovers() {
+        let (result, chunk) = compile_source("@ print 1;");
+        assert!(result.is_err());
+        assert_eq!(chunk.borrow().code().len(), 2);
+    }
+
+    #[test]
+    fn multiple_independent_syntax_errors_are_all_reported() {
+        let mut compiler = Compiler::from_source("1 + ; print 2; @ ; print 3;".to_owned());
+        let chunk: StoredChunk = rc_refcell!(Chunk::new());
+        let result = compiler.compile(chunk);
+
+        assert!(result.is_err());
+        assert_eq!(compiler.errors.len(), 3);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::chunk::{Chunk, OpCode};
+use crate::interner::Interner;
+use crate::value::Value;
+
+/// Prints a human-readable listing of `chunk`'s instructions, e.g.
+/// `0000  123 OP_CONST  2 '4.2'`. Only compiled in when the `disassemble`
+/// feature is enabled, so release builds pay nothing for it.
+pub fn disassemble_chunk(chunk: &Chunk, name: &str, interner: &Interner) {
+    println!("== {name} ==");
+    for (offset, op_code) in chunk.code().iter().enumerate() {
+        disassemble_instruction(chunk, offset, op_code, interner);
+    }
+}
+
+fn disassemble_instruction(chunk: &Chunk, offset: usize, op_code: &OpCode, interner: &Interner) {
+    print!("{offset:04} {:4} {}", line_of(op_code), mnemonic(op_code));
+
+    match op_code {
+        OpCode::Const { const_idx, .. }
+        | OpCode::DefineGlobal { const_idx, .. }
+        | OpCode::GetGlobal { const_idx, .. }
+        | OpCode::SetGlobal { const_idx, .. } => {
+            let value = chunk.constants()[*const_idx].borrow();
+            print!("  {const_idx} '{}'", display_value(&value, interner));
+        }
+        OpCode::GetLocal { slot, .. } | OpCode::SetLocal { slot, .. } => {
+            print!("  {slot}");
+        }
+        _ => {}
+    }
+
+    println!();
+}
+
+/// Renders `value` the way the disassembler wants it shown, resolving
+/// interned strings back to their text instead of printing the raw handle.
+fn display_value(value: &Value, interner: &Interner) -> String {
+    match value {
+        Value::Str(handle) => interner.resolve(*handle).to_owned(),
+        other => other.to_string(),
+    }
+}
+
+fn line_of(op_code: &OpCode) -> usize {
+    match op_code {
+        OpCode::Const { line, .. }
+        | OpCode::Negate { line }
+        | OpCode::Not { line }
+        | OpCode::Add { line }
+        | OpCode::Sub { line }
+        | OpCode::Mul { line }
+        | OpCode::Div { line }
+        | OpCode::Equal { line }
+        | OpCode::Greater { line }
+        | OpCode::Less { line }
+        | OpCode::Pop { line }
+        | OpCode::Print { line }
+        | OpCode::DefineGlobal { line, .. }
+        | OpCode::GetGlobal { line, .. }
+        | OpCode::SetGlobal { line, .. }
+        | OpCode::GetLocal { line, .. }
+        | OpCode::SetLocal { line, .. } => *line,
+    }
+}
+
+fn mnemonic(op_code: &OpCode) -> &'static str {
+    match op_code {
+        OpCode::Const { .. } => "OP_CONST",
+        OpCode::Negate { .. } => "OP_NEGATE",
+        OpCode::Not { .. } => "OP_NOT",
+        OpCode::Add { .. } => "OP_ADD",
+        OpCode::Sub { .. } => "OP_SUB",
+        OpCode::Mul { .. } => "OP_MUL",
+        OpCode::Div { .. } => "OP_DIV",
+        OpCode::Equal { .. } => "OP_EQUAL",
+        OpCode::Greater { .. } => "OP_GREATER",
+        OpCode::Less { .. } => "OP_LESS",
+        OpCode::Pop { .. } => "OP_POP",
+        OpCode::Print { .. } => "OP_PRINT",
+        OpCode::DefineGlobal { .. } => "OP_DEFINE_GLOBAL",
+        OpCode::GetGlobal { .. } => "OP_GET_GLOBAL",
+        OpCode::SetGlobal { .. } => "OP_SET_GLOBAL",
+        OpCode::GetLocal { .. } => "OP_GET_LOCAL",
+        OpCode::SetLocal { .. } => "OP_SET_LOCAL",
+    }
+}
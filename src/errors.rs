@@ -0,0 +1,12 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ParsingError {}
+
+impl fmt::Display for ParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parsing error")
+    }
+}
+
+impl std::error::Error for ParsingError {}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A handle into an `Interner`'s backing storage; cheap to copy and compare.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub struct InternedStr(usize);
+
+#[derive(Default, Debug)]
+pub struct Interner {
+    strings: Vec<String>,
+    handles: HashMap<String, InternedStr>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, value: &str) -> InternedStr {
+        if let Some(&handle) = self.handles.get(value) {
+            return handle;
+        }
+
+        let handle = InternedStr(self.strings.len());
+        self.strings.push(value.to_owned());
+        self.handles.insert(value.to_owned(), handle);
+        handle
+    }
+
+    pub fn resolve(&self, handle: InternedStr) -> &str {
+        &self.strings[handle.0]
+    }
+
+    /// The interned strings in handle order, i.e. `strings()[i]` is what
+    /// `InternedStr(i)` resolves to. Used to ship the string table alongside
+    /// a serialized chunk so its handles stay meaningful after a reload.
+    pub fn strings(&self) -> &[String] {
+        &self.strings
+    }
+}
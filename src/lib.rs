@@ -0,0 +1,14 @@
+pub mod alias;
+pub mod chunk;
+pub mod compiler;
+#[cfg(feature = "disassemble")]
+pub mod disassembler;
+pub mod errors;
+pub mod interner;
+#[macro_use]
+pub mod macros;
+pub mod parser;
+pub mod persist;
+pub mod scanner;
+pub mod token;
+pub mod value;
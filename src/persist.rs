@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::StoredChunk,
+    chunk::{Chunk, OpCode},
+    compiler::Compiler,
+    interner::Interner,
+    value::Value,
+};
+
+/// The on-disk shape of a compiled program: the code, the plain constant
+/// values (not the `Rc<RefCell<_>>` wrappers `Chunk` holds at runtime), and
+/// the interned string table those constants refer to, so `Value::Str`
+/// handles stay meaningful after a reload instead of pointing at a
+/// transient, per-compile interner.
+#[derive(Serialize, Deserialize)]
+struct SerializedChunk {
+    code: Vec<OpCode>,
+    constants: Vec<Value>,
+    strings: Vec<String>,
+}
+
+/// Compiles `source` and encodes the resulting chunk into a portable byte
+/// format that can be cached and loaded later via [`load_chunk`].
+pub fn compile_to_bytes(source: String) -> Vec<u8> {
+    let mut compiler = Compiler::from_source(source);
+    let chunk: StoredChunk = rc_refcell!(Chunk::new());
+    compiler
+        .compile(chunk.clone())
+        .expect("failed to compile source into a chunk");
+
+    let chunk = chunk.borrow();
+    let serialized = SerializedChunk {
+        code: chunk.code().to_vec(),
+        constants: chunk
+            .constants()
+            .iter()
+            .map(|value| value.borrow().clone())
+            .collect(),
+        strings: compiler.interner_strings().to_vec(),
+    };
+
+    bincode::serialize(&serialized).expect("failed to serialize chunk")
+}
+
+/// Decodes bytes produced by [`compile_to_bytes`] back into a runnable chunk,
+/// re-interning its string constants into a fresh [`Interner`] so that the
+/// chunk's `Value::Str` handles can still be resolved back to text.
+pub fn load_chunk(bytes: &[u8]) -> (StoredChunk, Interner) {
+    let serialized: SerializedChunk =
+        bincode::deserialize(bytes).expect("failed to deserialize chunk");
+
+    let mut interner = Interner::new();
+    for string in &serialized.strings {
+        interner.intern(string);
+    }
+
+    let mut chunk = Chunk::new();
+    for op_code in serialized.code {
+        chunk.push(op_code);
+    }
+    for value in serialized.constants {
+        chunk.push_const(rc_refcell!(value));
+    }
+
+    (rc_refcell!(chunk), interner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_preserves_code_and_deduplicated_string_constants() {
+        let source = "var x = \"dup\"; var y = \"dup\"; print x;".to_owned();
+        let bytes = compile_to_bytes(source);
+        let (chunk, interner) = load_chunk(&bytes);
+
+        let chunk = chunk.borrow();
+        assert_eq!(chunk.code().len(), 6);
+
+        let resolved: Vec<String> = chunk
+            .constants()
+            .iter()
+            .map(|value| match &*value.borrow() {
+                Value::Str(handle) => interner.resolve(*handle).to_owned(),
+                other => other.to_string(),
+            })
+            .collect();
+
+        // "dup" is interned once and its constant slot is reused for both
+        // `var` initializers, so it appears only once in the constant pool.
+        assert_eq!(resolved, vec!["x", "dup", "y"]);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::token::{Token, TokenType};
+
+pub struct Scanner {
+    source: Vec<char>,
+    start: usize,
+    current: usize,
+    line: usize,
+}
+
+impl Scanner {
+    pub fn new(source: String) -> Self {
+        Self {
+            source: source.chars().collect(),
+            start: 0,
+            current: 0,
+            line: 1,
+        }
+    }
+
+    pub fn substr(&self, start: usize, end: usize) -> String {
+        self.source[start..end].iter().collect()
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.current >= self.source.len()
+    }
+
+    fn advance(&mut self) -> char {
+        let c = self.source[self.current];
+        self.current += 1;
+        c
+    }
+
+    fn peek(&self) -> char {
+        if self.is_at_end() {
+            '\0'
+        } else {
+            self.source[self.current]
+        }
+    }
+
+    fn peek_next(&self) -> char {
+        if self.current + 1 >= self.source.len() {
+            '\0'
+        } else {
+            self.source[self.current + 1]
+        }
+    }
+
+    fn matches(&mut self, expected: char) -> bool {
+        if self.is_at_end() || self.source[self.current] != expected {
+            return false;
+        }
+        self.current += 1;
+        true
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            match self.peek() {
+                ' ' | '\r' | '\t' => {
+                    self.advance();
+                }
+                '\n' => {
+                    self.line += 1;
+                    self.advance();
+                }
+                '/' if self.peek_next() == '/' => {
+                    while self.peek() != '\n' && !self.is_at_end() {
+                        self.advance();
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn make_token(&self, token_type: TokenType) -> Token {
+        Token {
+            token_type,
+            start: self.start,
+            length: self.current - self.start,
+            line: self.line,
+            literal: None,
+            message: None,
+        }
+    }
+
+    fn error_token(&self, message: String) -> Token {
+        Token {
+            token_type: TokenType::Error,
+            start: self.start,
+            length: self.current - self.start,
+            line: self.line,
+            literal: None,
+            message: Some(message),
+        }
+    }
+
+    fn string(&mut self) -> Token {
+        while self.peek() != '"' && !self.is_at_end() {
+            if self.peek() == '\n' {
+                self.line += 1;
+            }
+            self.advance();
+        }
+
+        if self.is_at_end() {
+            return self.error_token("Unterminated string.".to_owned());
+        }
+
+        self.advance();
+        self.make_token(TokenType::String)
+    }
+
+    fn number(&mut self) -> Token {
+        while self.peek().is_ascii_digit() {
+            self.advance();
+        }
+
+        if self.peek() == '.' && self.peek_next().is_ascii_digit() {
+            self.advance();
+            while self.peek().is_ascii_digit() {
+                self.advance();
+            }
+        }
+
+        let mut token = self.make_token(TokenType::Number);
+        token.literal = Some(self.substr(self.start, self.current));
+        token
+    }
+
+    fn identifier_type(&self) -> TokenType {
+        match self.substr(self.start, self.current).as_str() {
+            "and" => TokenType::And,
+            "class" => TokenType::Class,
+            "else" => TokenType::Else,
+            "false" => TokenType::False,
+            "for" => TokenType::For,
+            "fun" => TokenType::Fun,
+            "if" => TokenType::If,
+            "nil" => TokenType::Nil,
+            "or" => TokenType::Or,
+            "print" => TokenType::Print,
+            "return" => TokenType::Return,
+            "super" => TokenType::Super,
+            "this" => TokenType::This,
+            "true" => TokenType::True,
+            "var" => TokenType::Var,
+            "while" => TokenType::While,
+            _ => TokenType::Identifier,
+        }
+    }
+
+    fn identifier(&mut self) -> Token {
+        while self.peek().is_ascii_alphanumeric() || self.peek() == '_' {
+            self.advance();
+        }
+        self.make_token(self.identifier_type())
+    }
+
+    pub fn scan_token(&mut self) -> Token {
+        self.skip_whitespace();
+        self.start = self.current;
+
+        if self.is_at_end() {
+            return self.make_token(TokenType::EOF);
+        }
+
+        let c = self.advance();
+
+        if c.is_ascii_alphabetic() || c == '_' {
+            return self.identifier();
+        }
+        if c.is_ascii_digit() {
+            return self.number();
+        }
+
+        match c {
+            '(' => self.make_token(TokenType::LeftParen),
+            ')' => self.make_token(TokenType::RightParen),
+            '{' => self.make_token(TokenType::LeftBrace),
+            '}' => self.make_token(TokenType::RightBrace),
+            ';' => self.make_token(TokenType::Semicolon),
+            ',' => self.make_token(TokenType::Comma),
+            '.' => self.make_token(TokenType::Dot),
+            '-' => self.make_token(TokenType::MINUS),
+            '+' => self.make_token(TokenType::PLUS),
+            '/' => self.make_token(TokenType::SLASH),
+            '*' => self.make_token(TokenType::STAR),
+            '!' => {
+                let token_type = if self.matches('=') {
+                    TokenType::BangEqual
+                } else {
+                    TokenType::Bang
+                };
+                self.make_token(token_type)
+            }
+            '=' => {
+                let token_type = if self.matches('=') {
+                    TokenType::EqualEqual
+                } else {
+                    TokenType::Equal
+                };
+                self.make_token(token_type)
+            }
+            '<' => {
+                let token_type = if self.matches('=') {
+                    TokenType::LessEqual
+                } else {
+                    TokenType::Less
+                };
+                self.make_token(token_type)
+            }
+            '>' => {
+                let token_type = if self.matches('=') {
+                    TokenType::GreaterEqual
+                } else {
+                    TokenType::Greater
+                };
+                self.make_token(token_type)
+            }
+            '"' => self.string(),
+            _ => self.error_token("Unexpected character.".to_owned()),
+        }
+    }
+}
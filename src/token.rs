@@ -0,0 +1,63 @@
+use std::fmt;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TokenType {
+    LeftParen,
+    RightParen,
+    LeftBrace,
+    RightBrace,
+    Comma,
+    Dot,
+    MINUS,
+    PLUS,
+    Semicolon,
+    SLASH,
+    STAR,
+    Bang,
+    BangEqual,
+    Equal,
+    EqualEqual,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+    SlashEqual,
+    Identifier,
+    String,
+    Number,
+    And,
+    Class,
+    Else,
+    False,
+    For,
+    Fun,
+    If,
+    Nil,
+    Or,
+    Print,
+    Return,
+    Super,
+    This,
+    True,
+    Var,
+    While,
+    Error,
+    EOF,
+}
+
+#[derive(Clone, Debug)]
+pub struct Token {
+    pub token_type: TokenType,
+    pub start: usize,
+    pub length: usize,
+    pub line: usize,
+    pub literal: Option<String>,
+    pub message: Option<String>,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{}", self.token_type, self.line)
+    }
+}
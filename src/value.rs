@@ -0,0 +1,31 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::interner::InternedStr;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Value {
+    Float(f64),
+    Bool(bool),
+    Nil,
+    Str(InternedStr),
+}
+
+/// `Str`'s handle prints as its raw debug form (e.g. `InternedStr(3)`), not
+/// the text it resolves to — `Display` has no interner to resolve through.
+/// This is fine for today's only consumer, the disassembler, which resolves
+/// strings itself before printing (see `disassembler::display_value`). Don't
+/// use this impl for user-facing string output; a future VM `print` will
+/// need to resolve `Value::Str` through its interner rather than relying on
+/// `Display`.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Float(n) => write!(f, "{n}"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Nil => write!(f, "nil"),
+            Value::Str(handle) => write!(f, "{handle:?}"),
+        }
+    }
+}